@@ -848,6 +848,7 @@ async fn render_app_async_helper(
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Actix's App without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generated Actix compatible paths.
+/// Routes are returned in the order described on [`RouteListing`].
 pub fn generate_route_list<IV>(
     app_fn: impl Fn() -> IV + 'static + Clone,
 ) -> Vec<RouteListing>
@@ -923,8 +924,9 @@ where
     // Actix's Router doesn't follow Leptos's
     // Match `*` or `*someword` to replace with replace it with "/{tail.*}
     let wildcard_re = Regex::new(r"\*.*").unwrap();
-    // Match `:some_word` but only capture `some_word` in the groups to replace with `{some_word}`
-    let capture_re = Regex::new(r":((?:[^.,/]+)+)[^/]?").unwrap();
+    // Match a `:some_word` segment but only capture `some_word` in the groups to replace with `{some_word}`.
+    // The name runs to the next `/`, so names like `:filter[status]` or `:user.id` stay intact.
+    let capture_re = Regex::new(r"(^|/):([^/]+)").unwrap();
 
     // Empty strings screw with Actix pathing, they need to be "/"
     routes = routes
@@ -952,7 +954,7 @@ where
             let path = wildcard_re
                 .replace_all(listing.path(), "{tail:.*}")
                 .to_string();
-            let path = capture_re.replace_all(&path, "{$1}").to_string();
+            let path = capture_re.replace_all(&path, "$1{$2}").to_string();
             RouteListing::new(
                 path,
                 listing.path(),
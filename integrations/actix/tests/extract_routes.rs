@@ -1,3 +1,4 @@
+use actix_web::dev::{Path, ResourceDef};
 use leptos::*;
 use leptos_actix::generate_route_list;
 use leptos_router::{Route, Router, Routes, TrailingSlash};
@@ -134,6 +135,35 @@ fn test_redirect_app() {
     );
 }
 
+#[component]
+fn StructuredParamsApp() -> impl IntoView {
+    let view = || view! { "" };
+    view! {
+        <Router>
+            <Routes>
+                <Route path="/search/:filter[status]/:user.id" view/>
+            </Routes>
+        </Router>
+    }
+}
+
+#[test]
+fn test_structured_param_names() {
+    let routes = generate_route_list(StructuredParamsApp);
+
+    assert_same(
+        &routes,
+        |r| r.path(),
+        &["/search/{filter[status]}/{user.id}"],
+    );
+
+    let resource = ResourceDef::new(routes[0].path());
+    let mut path = Path::new("/search/open/42");
+    assert!(resource.capture_match_info(&mut path));
+    assert_eq!(path.get("filter[status]"), Some("open"));
+    assert_eq!(path.get("user.id"), Some("42"));
+}
+
 fn assert_same<'t, T, F, U>(
     input: &'t Vec<T>,
     mapper: F,
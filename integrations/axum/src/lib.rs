@@ -1212,6 +1212,7 @@ where
 /// Generates a list of all routes defined in Leptos's Router in your app. We can then use this to automatically
 /// create routes in Axum's Router without having to use wildcard matching or fallbacks. Takes in your root app Element
 /// as an argument so it can walk you app tree. This version is tailored to generate Axum compatible paths.
+/// Routes are returned in the order described on [`RouteListing`].
 #[tracing::instrument(level = "trace", fields(error), skip_all)]
 pub fn generate_route_list<IV>(
     app_fn: impl Fn() -> IV + 'static + Clone,
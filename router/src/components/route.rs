@@ -18,9 +18,18 @@ thread_local! {
     static ROUTE_ID: Cell<usize> = const { Cell::new(0) };
 }
 
-// RouteDefinition.id is `pub` and required to be unique.
-// Should we make this public so users can generate unique IDs?
-pub(in crate::components) fn new_route_id() -> usize {
+/// Generates a new, unique ID for a [`RouteDefinition`].
+///
+/// IDs are drawn from a thread-local counter that is shared by every router
+/// and never reset, so an ID is never reused within a thread. IDs are unique,
+/// but they are neither dense nor stable: the router also allocates IDs for
+/// the redirect routes it creates, and `<Route/>` draws a fresh ID each time
+/// it is rendered. The same route will therefore have different IDs across
+/// renders, across threads, and between the server and the client.
+///
+/// Use this when building your own configuration-based routing, as
+/// `RouteDefinition.id` is required to be unique.
+pub fn new_route_id() -> usize {
     ROUTE_ID.with(|id| {
         let next = id.get() + 1;
         id.set(next);
@@ -60,7 +69,9 @@ pub enum Method {
 pub fn Route<E, F, P>(
     /// The path fragment that this route should match. This can be static (`users`),
     /// include a parameter (`:id`) or an optional parameter (`:id?`), or match a
-    /// wildcard (`user/*any`).
+    /// wildcard (`user/*any`). Static segments are matched byte-for-byte against the URL,
+    /// unless `decode_static_segments` is set on the [`Router`](crate::Router), in which
+    /// case they also match their percent-encoded form (`hello world` matches `hello%20world`).
     path: P,
     /// The view that should be shown when this route is matched. This can be any function
     /// that returns a type that implements [`IntoView`] (like `|| view! { <p>"Show this"</p> })`
@@ -146,7 +157,9 @@ where
 pub fn ProtectedRoute<P, E, F, C>(
     /// The path fragment that this route should match. This can be static (`users`),
     /// include a parameter (`:id`) or an optional parameter (`:id?`), or match a
-    /// wildcard (`user/*any`).
+    /// wildcard (`user/*any`). Static segments are matched byte-for-byte against the URL,
+    /// unless `decode_static_segments` is set on the [`Router`](crate::Router), in which
+    /// case they also match their percent-encoded form (`hello world` matches `hello%20world`).
     path: P,
     /// The path that will be redirected to if the condition is `false`.
     redirect_path: P,
@@ -211,7 +224,9 @@ where
 pub fn StaticRoute<E, F, P, S>(
     /// The path fragment that this route should match. This can be static (`users`),
     /// include a parameter (`:id`) or an optional parameter (`:id?`), or match a
-    /// wildcard (`user/*any`).
+    /// wildcard (`user/*any`). Static segments are matched byte-for-byte against the URL,
+    /// unless `decode_static_segments` is set on the [`Router`](crate::Router), in which
+    /// case they also match their percent-encoded form (`hello world` matches `hello%20world`).
     path: P,
     /// The view that should be shown when this route is matched. This can be any function
     /// that returns a type that implements [IntoView] (like `|| view! { <p>"Show this"</p> })`
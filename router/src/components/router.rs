@@ -38,6 +38,17 @@ pub fn Router(
     /// How trailing slashes should be handled in [`Route`] paths.
     #[prop(optional)]
     trailing_slash: TrailingSlash,
+    /// Whether static segments in [`Route`](crate::Route) paths should also match URL segments
+    /// that equal them once percent-decoded. Defaults to `false`.
+    ///
+    /// By default, static segments are compared byte-for-byte with the URL, so
+    /// `path="/hello world"` does not match `/hello%20world`. If this is `true`,
+    /// it does. Note that this also lets non-canonical encodings like `/%61dmin`
+    /// match `path="/admin"`, while the server integrations still match static
+    /// paths byte-for-byte, so any server-side handling attached to a route's
+    /// path will not see these requests.
+    #[prop(optional)]
+    decode_static_segments: bool,
     /// The `<Router/>` should usually wrap your whole page. It can contain
     /// any elements, and should include a [`Routes`](crate::Routes) component somewhere
     /// to define and display [`Route`](crate::Route)s.
@@ -48,7 +59,13 @@ pub fn Router(
     id: usize,
 ) -> impl IntoView {
     // create a new RouterContext and provide it to every component beneath the router
-    let router = RouterContext::new(id, base, fallback, trailing_slash);
+    let router = RouterContext::new(
+        id,
+        base,
+        fallback,
+        trailing_slash,
+        decode_static_segments,
+    );
     provide_context(router);
     provide_context(GlobalSuspenseContext::new());
     if let Some(set_is_routing) = set_is_routing {
@@ -105,6 +122,7 @@ pub(crate) struct RouterContextInner {
     pub location: Location,
     pub base: RouteContext,
     trailing_slash: TrailingSlash,
+    decode_static_segments: bool,
     pub possible_routes: RefCell<Option<Vec<Branch>>>,
     #[allow(unused)] // used in CSR/hydrate
     base_path: String,
@@ -143,6 +161,7 @@ impl RouterContext {
         base: Option<&'static str>,
         fallback: Option<fn() -> View>,
         trailing_slash: TrailingSlash,
+        decode_static_segments: bool,
     ) -> Self {
         cfg_if! {
             if #[cfg(any(feature = "csr", feature = "hydrate"))] {
@@ -225,6 +244,7 @@ impl RouterContext {
             location,
             base,
             trailing_slash,
+            decode_static_segments,
             history: Box::new(history),
 
             reference,
@@ -267,6 +287,10 @@ impl RouterContext {
         self.inner.trailing_slash.clone()
     }
 
+    pub(crate) fn decode_static_segments(&self) -> bool {
+        self.inner.decode_static_segments
+    }
+
     /// A list of all possible routes this router can match.
     pub fn possible_branches(&self) -> Vec<Branch> {
         self.inner
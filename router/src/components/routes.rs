@@ -325,6 +325,7 @@ impl Branches {
                     &mut branches,
                     true,
                     base,
+                    router.decode_static_segments(),
                 );
                 current.insert((router.id(), Cow::Owned(base.into())), branches);
             }
@@ -621,6 +622,7 @@ fn create_branches(
     branches: &mut Vec<Branch>,
     static_valid: bool,
     parents_path: &str,
+    decode_static: bool,
 ) {
     for def in route_defs {
         let routes = create_routes(
@@ -628,6 +630,7 @@ fn create_branches(
             base,
             static_valid && def.static_mode.is_some(),
             parents_path,
+            decode_static,
         );
         for route in routes {
             stack.push(route.clone());
@@ -643,6 +646,7 @@ fn create_branches(
                     branches,
                     static_valid && route.key.static_mode.is_some(),
                     &format!("{}{}", parents_path, def.path),
+                    decode_static,
                 );
             }
 
@@ -671,6 +675,7 @@ fn create_routes(
     base: &str,
     static_valid: bool,
     parents_path: &str,
+    decode_static: bool,
 ) -> Vec<RouteData> {
     let RouteDefinition { children, .. } = route_def;
     let is_leaf = children.is_empty();
@@ -700,7 +705,8 @@ fn create_routes(
         let route_data = RouteData {
             key: route_def.clone(),
             id: route_def.id,
-            matcher: Matcher::new_with_partial(&pattern, !is_leaf),
+            matcher: Matcher::new_with_partial(&pattern, !is_leaf)
+                .with_decoded_static_segments(decode_static),
             pattern,
             original_path: original_path.into_owned(),
         };
@@ -711,7 +717,8 @@ fn create_routes(
             let pattern = &redirect_route.path;
             let redirect_route_data = RouteData {
                 id: redirect_route.id,
-                matcher: Matcher::new_with_partial(pattern, !is_leaf),
+                matcher: Matcher::new_with_partial(pattern, !is_leaf)
+                    .with_decoded_static_segments(decode_static),
                 pattern: pattern.to_owned(),
                 original_path: pattern.to_owned(),
                 key: redirect_route,
@@ -788,3 +795,73 @@ fn FixTrailingSlash(add_slash: bool) -> impl IntoView {
         <Redirect path options/>
     }
 }
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use super::*;
+    use crate::{Method, SsrMode};
+
+    fn route(path: &str, children: Vec<RouteDefinition>) -> RouteDefinition {
+        RouteDefinition {
+            id: new_route_id(),
+            path: path.to_string(),
+            children,
+            view: Rc::new(|| ().into_view()),
+            ssr_mode: SsrMode::default(),
+            methods: &[Method::Get],
+            data: None,
+            static_mode: None,
+            static_params: None,
+            trailing_slash: Some(TrailingSlash::Drop),
+        }
+    }
+
+    fn branch_patterns(routes: &[RouteDefinition]) -> Vec<String> {
+        let mut branches = Vec::new();
+        create_branches(
+            routes,
+            "",
+            &mut Vec::new(),
+            &mut branches,
+            true,
+            "",
+            false,
+        );
+        branches
+            .iter()
+            .map(|branch| branch.routes.last().unwrap().pattern.clone())
+            .collect()
+    }
+
+    #[test]
+    fn branches_with_equal_score_keep_declaration_order() {
+        let routes = [
+            route("/a", vec![route("/x", vec![]), route("/y", vec![])]),
+            route("/b", vec![route("/z", vec![])]),
+            route("/c/v", vec![]),
+        ];
+        assert_eq!(branch_patterns(&routes), ["/a/x", "/a/y", "/b/z", "/c/v"]);
+    }
+
+    #[test]
+    fn higher_score_beats_declaration_order() {
+        let routes = [
+            route("/c", vec![route("/v", vec![])]),
+            route("/b", vec![route("/z", vec![route("/w", vec![])])]),
+        ];
+        assert_eq!(branch_patterns(&routes), ["/b/z/w", "/c/v"]);
+    }
+
+    #[test]
+    fn more_specific_branches_come_first() {
+        let routes = [
+            route("/users/*any", vec![]),
+            route("/users/:id", vec![]),
+            route("/users/new", vec![]),
+        ];
+        assert_eq!(
+            branch_patterns(&routes),
+            ["/users/new", "/users/:id", "/users/*any"]
+        );
+    }
+}
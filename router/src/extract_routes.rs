@@ -18,6 +18,12 @@ pub struct PossibleBranchContext(pub(crate) Rc<RefCell<Vec<Branch>>>);
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 /// A route that this application can serve.
+///
+/// Route lists are ordered the same way the router tests routes: most specific
+/// first (static segments over params over wildcards, longer paths first).
+/// Routes that are equally specific keep the order in which they were
+/// declared, with nested routes listed depth-first. The order is stable
+/// between runs for the same route tree.
 pub struct RouteListing {
     path: String,
     leptos_path: String,
@@ -109,6 +115,8 @@ impl RouteListing {
 /// format. Odds are you want `generate_route_list()` from either the [`actix`] or [`axum`] integrations if you want
 /// to work with their router.
 ///
+/// Routes are listed in the order described on [`RouteListing`].
+///
 /// [`actix`]: <https://docs.rs/actix/>
 /// [`axum`]: <https://docs.rs/axum/>
 pub fn generate_route_list_inner<IV>(
@@ -123,6 +131,8 @@ where
 /// format. Odds are you want `generate_route_list()` from either the [`actix`] or [`axum`] integrations if you want
 /// to work with their router.
 ///
+/// Routes are listed in the order described on [`RouteListing`].
+///
 /// [`actix`]: <https://docs.rs/actix/>
 /// [`axum`]: <https://docs.rs/axum/>
 pub fn generate_route_list_inner_with_context<IV>(
@@ -1,7 +1,7 @@
 // Implementation based on Solid Router
 // see <https://github.com/solidjs/solid-router/blob/main/src/utils.ts>
 
-use crate::ParamsMap;
+use crate::{expand_optionals, ParamsMap};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[doc(hidden)]
@@ -17,6 +17,7 @@ pub struct Matcher {
     segments: Vec<String>,
     len: usize,
     partial: bool,
+    decode_static: bool,
 }
 
 impl Matcher {
@@ -38,24 +39,52 @@ impl Matcher {
             segments,
             len,
             partial,
+            decode_static: false,
         }
     }
 
+    /// Sets whether static segments should also match location segments that
+    /// equal them once percent-decoded, e.g. `/hello%20world` for a static
+    /// segment `hello world`. Off by default, which compares segments
+    /// byte-for-byte.
+    #[doc(hidden)]
+    pub fn with_decoded_static_segments(mut self, decode: bool) -> Self {
+        self.decode_static = decode;
+        self
+    }
+
+    /// Tests whether `location` matches this pattern. A missing leading slash
+    /// is equivalent to a present one, so `users/42` matches exactly like
+    /// `/users/42`.
     #[doc(hidden)]
     pub fn test(&self, location: &str) -> Option<PathMatch> {
-        let loc_segments: Vec<&str> = get_segments(location);
+        self.test_split_path(&SplitPath::from_path(location))
+    }
+
+    /// Tests a location that has already been split with [`SplitPath`], which
+    /// avoids re-scanning the same path when testing it against many matchers.
+    /// This is the only pre-split entry point; [`Matcher::test`] is equivalent
+    /// to testing `SplitPath::new(location)` for a location without a query or
+    /// hash.
+    #[doc(hidden)]
+    pub fn test_split_path(&self, path: &SplitPath<'_>) -> Option<PathMatch> {
+        self.test_split(&path.segments, path.trailing_slashes)
+    }
 
+    fn test_split(
+        &self,
+        loc_segments: &[&str],
+        trailing_slashes: usize,
+    ) -> Option<PathMatch> {
         let loc_len = loc_segments.len();
         let len_diff: i32 = loc_len as i32 - self.len as i32;
 
-        let trailing_iter = location.chars().rev().take_while(|n| *n == '/');
-
         // quick path: not a match if
         // 1) matcher has add'l segments not found in location
         // 2) location has add'l segments, there's no splat, and partial matches not allowed
         if loc_len < self.len
             || (len_diff > 0 && self.splat.is_none() && !self.partial)
-            || (self.splat.is_none() && trailing_iter.clone().count() > 1)
+            || (self.splat.is_none() && trailing_slashes > 1)
         {
             None
         }
@@ -69,7 +98,9 @@ impl Matcher {
             {
                 if let Some(param_name) = segment.strip_prefix(':') {
                     params.insert(param_name.into(), (*loc_segment).into());
-                } else if segment != loc_segment {
+                } else if segment != loc_segment
+                    && !(self.decode_static && decodes_to(loc_segment, segment))
+                {
                     // if any segment doesn't match and isn't a param, there's no path match
                     return None;
                 }
@@ -87,9 +118,9 @@ impl Matcher {
                     };
 
                     // add trailing slashes to splat
-                    let trailing_slashes =
-                        trailing_iter.skip(1).collect::<String>();
-                    value.push_str(&trailing_slashes);
+                    for _ in 1..trailing_slashes {
+                        value.push('/');
+                    }
 
                     params.insert(splat.into(), value);
                 }
@@ -99,14 +130,146 @@ impl Matcher {
         }
     }
 
+    /// Returns the names of the params this matcher captures, in the order
+    /// they appear in the path, followed by the splat name if there is one.
+    /// These are exactly the keys [`Matcher::test`] inserts; for a route path
+    /// that may contain optional params, use [`route_param_names`].
+    #[doc(hidden)]
+    pub fn param_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.segments
+            .iter()
+            .filter_map(|segment| segment.strip_prefix(':'))
+            .chain(self.splat.as_deref().filter(|splat| !splat.is_empty()))
+    }
+
     #[doc(hidden)]
     pub(crate) fn is_wildcard(&self) -> bool {
         self.splat.is_some()
     }
 }
 
+/// Returns the names of the params a route path declares, in the order they
+/// appear in the path, followed by the splat name if there is one.
+///
+/// The path is expanded with [`expand_optionals`] first, so optional params
+/// (`:id?`) are listed by the name they are captured under, without the `?`.
+#[doc(hidden)]
+pub fn route_param_names(path: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    // the last expansion keeps every optional segment, so visiting it first
+    // lists the names in path order
+    for expanded in expand_optionals(path).iter().rev() {
+        for name in Matcher::new(expanded).param_names() {
+            if !names.iter().any(|seen| seen == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// A location that has been split into its path segments, query and hash
+/// once, so that it can be tested against many [`Matcher`]s.
+#[doc(hidden)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitPath<'a> {
+    segments: Vec<&'a str>,
+    trailing_slashes: usize,
+    query: Option<&'a str>,
+    hash: Option<&'a str>,
+}
+
+impl<'a> SplitPath<'a> {
+    #[doc(hidden)]
+    pub fn new(location: &'a str) -> Self {
+        let (location, hash) = match location.split_once('#') {
+            Some((location, hash)) => (location, Some(hash)),
+            None => (location, None),
+        };
+        let (path, query) = match location.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (location, None),
+        };
+        Self {
+            query,
+            hash,
+            ..Self::from_path(path)
+        }
+    }
+
+    /// Builds a split path from segments the caller already has, e.g. from
+    /// [`SplitPath::segments`] of an earlier parse, without re-scanning a
+    /// string. Empty segments are skipped, except that a trailing empty
+    /// segment means exactly one trailing slash; a location with several
+    /// trailing slashes (`/foo//`) can't be represented this way.
+    #[doc(hidden)]
+    pub fn from_segments(segments: &[&'a str]) -> Self {
+        let mut split: Vec<&'a str> = segments
+            .iter()
+            .copied()
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        let trailing_slash = !split.is_empty() && segments.last() == Some(&"");
+        if trailing_slash {
+            split.push("");
+        }
+        Self {
+            segments: split,
+            trailing_slashes: trailing_slash as usize,
+            query: None,
+            hash: None,
+        }
+    }
+
+    // splits a bare path, treating any `?` or `#` as part of a segment
+    fn from_path(path: &'a str) -> Self {
+        Self {
+            segments: get_segments(path),
+            trailing_slashes: path
+                .chars()
+                .rev()
+                .take_while(|n| *n == '/')
+                .count(),
+            query: None,
+            hash: None,
+        }
+    }
+
+    /// The path segments. Empty segments are omitted, except that a trailing
+    /// slash on a non-root path is represented by an empty segment at the end.
+    #[doc(hidden)]
+    pub fn segments(&self) -> &[&'a str] {
+        &self.segments
+    }
+
+    /// The query string, without the leading `?`.
+    #[doc(hidden)]
+    pub fn query(&self) -> Option<&'a str> {
+        self.query
+    }
+
+    /// The hash, without the leading `#`.
+    #[doc(hidden)]
+    pub fn hash(&self) -> Option<&'a str> {
+        self.hash
+    }
+}
+
+// Static segments are written decoded in route definitions (`/hello world`),
+// but arrive percent-encoded in the location (`/hello%20world`). When static
+// decoding is enabled, a segment matches if either its raw or its decoded form
+// equals the static segment.
+fn decodes_to(encoded: &str, decoded: &str) -> bool {
+    encoded.contains('%')
+        && percent_encoding::percent_decode_str(encoded)
+            .decode_utf8()
+            .map(|value| value == decoded)
+            .unwrap_or(false)
+}
+
 fn get_segments<'a, S: From<&'a str>>(pattern: &'a str) -> Vec<S> {
     // URL root paths ("/" and "") are equivalent and treated as 0-segment paths.
+    // A missing leading slash is equivalent, too: "users/42" splits like "/users/42".
     // non-root paths with trailing slashes get extra empty segment at the end.
     // This makes sure that segment matching is trailing-slash sensitive.
     let mut segments: Vec<S> = pattern
@@ -49,6 +49,8 @@ fn build_route_matches(
     base: &str,
     location: String,
 ) -> Rc<Vec<RouteMatch>> {
+    // split the location once, rather than once per route of every branch
+    let location = SplitPath::new(&location);
     Rc::new(Branches::with(router_id, base, |branches| {
         for branch in branches {
             if let Some(matches) = branch.matcher(&location) {
@@ -60,6 +62,10 @@ fn build_route_matches(
 }
 
 /// Describes a branch of the route tree.
+///
+/// Branches are tested in descending order of [`score`](Branch::score), so the
+/// most specific route wins. Branches with the same score keep the order in
+/// which their routes were declared, walking nested routes depth-first.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Branch {
     /// All the routes contained in the branch.
@@ -69,10 +75,10 @@ pub struct Branch {
 }
 
 impl Branch {
-    fn matcher<'a>(&'a self, location: &'a str) -> Option<Vec<RouteMatch>> {
+    fn matcher(&self, location: &SplitPath<'_>) -> Option<Vec<RouteMatch>> {
         let mut matches = Vec::new();
         for route in self.routes.iter().rev() {
-            match route.matcher.test(location) {
+            match route.matcher.test_split_path(location) {
                 None => return None,
                 Some(m) => matches.push(RouteMatch {
                     path_match: m,
@@ -7,7 +7,7 @@ use std::rc::Rc;
 /// used to build your own configuration-based or filesystem-based routing.
 #[derive(Clone)]
 pub struct RouteDefinition {
-    /// A unique ID for each route.
+    /// A unique ID for each route. This can be generated with [`new_route_id`](crate::new_route_id).
     pub id: usize,
     /// The path. This can include params like `:id` or wildcards like `*all`.
     pub path: String,
@@ -32,6 +32,7 @@ pub struct RouteDefinition {
 impl core::fmt::Debug for RouteDefinition {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("RouteDefinition")
+            .field("id", &self.id)
             .field("path", &self.path)
             .field("children", &self.children)
             .field("ssr_mode", &self.ssr_mode)
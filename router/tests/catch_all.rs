@@ -0,0 +1,22 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "ssr")] {
+        use leptos_router::{params_map, Matcher};
+
+        #[test]
+        fn catch_all_route_captures_unknown_path() {
+            let matcher = Matcher::new("/*any");
+            let matched = matcher.test("/does/not/exist").unwrap();
+            assert_eq!(matched.params, params_map! { "any" => "does/not/exist" });
+        }
+
+        #[test]
+        fn nested_catch_all_route_captures_unmatched_tail() {
+            let matcher = Matcher::new("/docs/*any");
+            let matched = matcher.test("/docs/missing/page/").unwrap();
+            assert_eq!(matched.path, "/docs");
+            assert_eq!(matched.params, params_map! { "any" => "missing/page/" });
+        }
+    }
+}
@@ -153,5 +153,18 @@ cfg_if! {
                 })
             );
         }
+
+        #[test]
+        fn leading_slash_is_optional() {
+            let matcher = Matcher::new("/users/:id");
+            assert_eq!(matcher.test("users/42"), matcher.test("/users/42"));
+            assert_eq!(matcher.test("users/42").unwrap().path, "/users/42");
+
+            let matcher = Matcher::new("users/:id");
+            assert_eq!(
+                matcher.test("/users/42"),
+                Matcher::new("/users/:id").test("/users/42")
+            );
+        }
     }
 }
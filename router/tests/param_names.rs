@@ -0,0 +1,94 @@
+use leptos_router::*;
+
+#[test]
+fn param_names_are_in_path_order() {
+    let matcher = Matcher::new("/users/:user_id/posts/:post_id");
+    assert_eq!(
+        matcher.param_names().collect::<Vec<_>>(),
+        ["user_id", "post_id"]
+    );
+}
+
+#[test]
+fn param_names_include_splat() {
+    let matcher = Matcher::new("/files/:owner/*path");
+    assert_eq!(matcher.param_names().collect::<Vec<_>>(), ["owner", "path"]);
+
+    // an unnamed splat does not capture a param
+    let matcher = Matcher::new("/files/*");
+    assert_eq!(matcher.param_names().count(), 0);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn param_names_are_the_keys_test_captures() {
+    // an unexpanded optional param is captured under its raw name
+    let matcher = Matcher::new("/posts/:page?");
+    assert_eq!(matcher.param_names().collect::<Vec<_>>(), ["page?"]);
+
+    let matched = matcher.test("/posts/7").unwrap();
+    let captured = matched
+        .params
+        .0
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    assert_eq!(captured, matcher.param_names().collect::<Vec<_>>());
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn route_param_names_include_optional_params() {
+    assert_eq!(
+        route_param_names("/posts/:category?/:page?"),
+        ["category", "page"]
+    );
+    assert_eq!(route_param_names("/:lang?/docs/:page"), ["lang", "page"]);
+    assert_eq!(
+        route_param_names("/users/:user_id/files/*path"),
+        ["user_id", "path"]
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn route_param_names_agree_with_expanded_matchers() {
+    let pattern = "/posts/:category?/:page";
+    let mut expanded = expand_optionals(pattern)
+        .iter()
+        .flat_map(|path| {
+            Matcher::new(path)
+                .param_names()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    expanded.sort();
+    expanded.dedup();
+
+    let mut names = route_param_names(pattern);
+    names.sort();
+    assert_eq!(names, expanded);
+}
+
+#[test]
+fn param_names_of_static_path_are_empty() {
+    let matcher = Matcher::new("/about/team");
+    assert_eq!(matcher.param_names().count(), 0);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn param_names_may_contain_dots_and_brackets() {
+    let matcher = Matcher::new("/search/:filter[status]/:user.id");
+    assert_eq!(
+        matcher.param_names().collect::<Vec<_>>(),
+        ["filter[status]", "user.id"]
+    );
+
+    let matched = matcher.test("/search/open/42").unwrap();
+    assert_eq!(
+        matched.params,
+        params_map! { "filter[status]" => "open", "user.id" => "42" }
+    );
+}
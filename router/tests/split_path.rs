@@ -0,0 +1,130 @@
+use leptos_router::*;
+
+#[test]
+fn split_path_matches_same_as_test() {
+    for (pattern, location) in [
+        ("/foo/bar", "/foo/bar"),
+        ("/foo/bar", "/foo/baz"),
+        ("/foo/bar", "/foo/bar/"),
+        ("/foo/", "/foo/"),
+        ("/foo/", "/foo"),
+        ("/foo/", "/foo//"),
+        ("/", "/"),
+        ("/", ""),
+    ] {
+        let matcher = Matcher::new(pattern);
+        assert_eq!(
+            matcher.test_split_path(&SplitPath::new(location)),
+            matcher.test(location),
+            "{pattern:?} against {location:?}"
+        );
+    }
+}
+
+#[test]
+fn split_path_rejects_multiple_trailing_slashes() {
+    let split = SplitPath::new("/foo//");
+    assert_eq!(split.segments(), ["foo", ""]);
+    assert_eq!(Matcher::new("/foo/").test_split_path(&split), None);
+}
+
+#[test]
+fn split_path_from_segments_matches_same_as_test() {
+    for (pattern, location) in [
+        ("/foo/bar", "/foo/bar"),
+        ("/foo/bar", "/foo/baz"),
+        ("/foo/bar", "/foo/bar/"),
+        ("/foo/", "/foo/"),
+        ("/foo/", "/foo"),
+        ("/", "/"),
+        ("/", ""),
+    ] {
+        let matcher = Matcher::new(pattern);
+        let parsed = SplitPath::new(location);
+        assert_eq!(
+            matcher
+                .test_split_path(&SplitPath::from_segments(parsed.segments())),
+            matcher.test(location),
+            "{pattern:?} against {location:?}"
+        );
+    }
+}
+
+#[test]
+fn split_path_from_segments_trailing_empty_is_one_slash() {
+    let split = SplitPath::from_segments(&["foo", ""]);
+    assert_eq!(split.segments(), ["foo", ""]);
+    assert!(Matcher::new("/foo/").test_split_path(&split).is_some());
+    assert!(Matcher::new("/foo").test_split_path(&split).is_none());
+
+    // inner and root empty segments are skipped
+    let split = SplitPath::from_segments(&["", "foo", "", "bar"]);
+    assert_eq!(split.segments(), ["foo", "bar"]);
+    let empty: &[&str] = &[];
+    assert_eq!(SplitPath::from_segments(&[""]).segments(), empty);
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn split_path_builds_params() {
+    let matcher = Matcher::new("/users/:id/*rest");
+    let matched = matcher.test_split_path(&SplitPath::new("/users/42/posts/7"));
+    assert_eq!(matched, matcher.test("/users/42/posts/7"));
+    assert_eq!(
+        matched.unwrap().params,
+        params_map! { "id" => "42", "rest" => "posts/7" }
+    );
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn split_path_against_many_matchers() {
+    let split = SplitPath::new("/blog/hello-world");
+    let matchers = [
+        Matcher::new("/"),
+        Matcher::new("/about"),
+        Matcher::new("/blog/:slug"),
+    ];
+    let matched = matchers
+        .iter()
+        .position(|matcher| matcher.test_split_path(&split).is_some());
+    assert_eq!(matched, Some(2));
+}
+
+#[test]
+fn split_path_handles_edge_cases() {
+    let empty: &[&str] = &[];
+
+    let split = SplitPath::new("");
+    assert_eq!(split.segments(), empty);
+    assert_eq!(split.query(), None);
+    assert_eq!(split.hash(), None);
+
+    assert_eq!(SplitPath::new("/").segments(), empty);
+    assert_eq!(SplitPath::new("/foo/bar").segments(), ["foo", "bar"]);
+    assert_eq!(SplitPath::new("/foo/bar/").segments(), ["foo", "bar", ""]);
+
+    let split = SplitPath::new("/foo/?q=a/b#top");
+    assert_eq!(split.segments(), ["foo", ""]);
+    assert_eq!(split.query(), Some("q=a/b"));
+    assert_eq!(split.hash(), Some("top"));
+
+    let split = SplitPath::new("/foo#section?not-a-query");
+    assert_eq!(split.segments(), ["foo"]);
+    assert_eq!(split.query(), None);
+    assert_eq!(split.hash(), Some("section?not-a-query"));
+
+    let split = SplitPath::new("/?q=1");
+    assert_eq!(split.segments(), empty);
+    assert_eq!(split.query(), Some("q=1"));
+}
+
+#[cfg(feature = "ssr")]
+#[test]
+fn split_path_keeps_trailing_slashes_in_splat() {
+    let split = SplitPath::new("/foo/bar////");
+    let matcher = Matcher::new("/foo/bar/*any");
+    let matched = matcher.test_split_path(&split);
+    assert_eq!(matched, matcher.test("/foo/bar////"));
+    assert_eq!(matched.unwrap().params, params_map! { "any" => "///" });
+}
@@ -0,0 +1,43 @@
+use leptos_router::*;
+
+fn decoding(path: &str) -> Matcher {
+    Matcher::new(path).with_decoded_static_segments(true)
+}
+
+#[test]
+fn static_segments_match_byte_for_byte_by_default() {
+    let matcher = Matcher::new("/hello world");
+    assert!(matcher.test("/hello world").is_some());
+    assert!(matcher.test("/hello%20world").is_none());
+
+    let matcher = Matcher::new("/admin");
+    assert!(matcher.test("/admin").is_some());
+    assert!(matcher.test("/%61dmin").is_none());
+}
+
+#[test]
+fn static_segments_match_percent_encoded_location() {
+    let matcher = decoding("/hello world/caf\u{e9}");
+    let matched = matcher.test("/hello%20world/caf%C3%A9").unwrap();
+    // the matched path is the location as given, not the decoded route
+    assert_eq!(matched.path, "/hello%20world/caf%C3%A9");
+
+    assert!(matcher.test("/hello world/caf\u{e9}").is_some());
+    assert!(matcher.test("/hello%20there/caf%C3%A9").is_none());
+
+    // any encoding of the same segment matches, not just the canonical one
+    assert!(decoding("/admin").test("/%61dmin").is_some());
+}
+
+#[test]
+fn static_segments_declared_encoded_still_match_raw() {
+    let matcher = decoding("/100%25");
+    assert!(matcher.test("/100%25").is_some());
+    assert!(matcher.test("/100%").is_none());
+}
+
+#[test]
+fn invalid_percent_encoding_does_not_match() {
+    let matcher = decoding("/hello");
+    assert!(matcher.test("/hell%FF").is_none());
+}